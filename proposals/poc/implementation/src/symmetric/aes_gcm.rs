@@ -0,0 +1,132 @@
+use super::key::SymmetricKey;
+use super::tag::SymmetricTag;
+use super::{SymmetricAlgorithm, SymmetricOptions};
+use crate::error::*;
+use ring::aead;
+
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+pub struct AesGcmState {
+    key: SymmetricKey,
+    nonce: [u8; NONCE_LEN],
+    auto_nonce: bool,
+    counter: u64,
+}
+
+impl AesGcmState {
+    pub fn new(
+        key: &SymmetricKey,
+        options: &SymmetricOptions,
+    ) -> Result<Self, CryptoError> {
+        let raw_nonce = options.get("nonce").map_err(|_| CryptoError::NonceRequired)?;
+        if raw_nonce.len() != NONCE_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce.copy_from_slice(&raw_nonce);
+        Ok(AesGcmState {
+            key: key.clone(),
+            nonce,
+            auto_nonce: false,
+            counter: 0,
+        })
+    }
+
+    /// A state whose nonce auto-increments on every `encrypt`/`decrypt` call,
+    /// for the directional transport keys produced by `SymmetricState::split`.
+    pub fn new_auto(key: &SymmetricKey) -> Self {
+        AesGcmState {
+            key: key.clone(),
+            nonce: [0u8; NONCE_LEN],
+            auto_nonce: true,
+            counter: 0,
+        }
+    }
+
+    fn next_nonce(&mut self) -> [u8; NONCE_LEN] {
+        if !self.auto_nonce {
+            return self.nonce;
+        }
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce[4..].copy_from_slice(&self.counter.to_be_bytes());
+        self.counter += 1;
+        nonce
+    }
+
+    fn algorithm(&self) -> Result<&'static aead::Algorithm, CryptoError> {
+        match self.key.alg() {
+            SymmetricAlgorithm::Aes128Gcm => Ok(&aead::AES_128_GCM),
+            SymmetricAlgorithm::Aes256Gcm => Ok(&aead::AES_256_GCM),
+            _ => bail!(CryptoError::UnsupportedAlgorithm),
+        }
+    }
+
+    fn less_safe_key(&self) -> Result<aead::LessSafeKey, CryptoError> {
+        let unbound = aead::UnboundKey::new(self.algorithm()?, self.key.as_bytes())
+            .map_err(|_| CryptoError::InvalidKey)?;
+        Ok(aead::LessSafeKey::new(unbound))
+    }
+
+    pub fn options_get(&self, name: &str) -> Result<Vec<u8>, CryptoError> {
+        match name.to_lowercase().as_str() {
+            "nonce" => Ok(self.nonce.to_vec()),
+            _ => bail!(CryptoError::UnsupportedOption),
+        }
+    }
+
+    pub fn max_tag_len(&self) -> Result<usize, CryptoError> {
+        Ok(TAG_LEN)
+    }
+
+    pub fn encrypt_detached(&mut self, out: &mut [u8], data: &[u8]) -> Result<SymmetricTag, CryptoError> {
+        if out.len() != data.len() {
+            bail!(CryptoError::InvalidLength);
+        }
+        let key = self.less_safe_key()?;
+        let nonce = aead::Nonce::assume_unique_for_key(self.next_nonce());
+        out.copy_from_slice(data);
+        let tag = key
+            .seal_in_place_separate_tag(nonce, aead::Aad::empty(), out)
+            .map_err(|_| CryptoError::AlgorithmFailure)?;
+        Ok(SymmetricTag::new(tag.as_ref().to_vec()))
+    }
+
+    pub fn encrypt(&mut self, out: &mut [u8], data: &[u8]) -> Result<usize, CryptoError> {
+        if out.len() != data.len() + TAG_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let (ciphertext, raw_tag) = out.split_at_mut(data.len());
+        let tag = self.encrypt_detached(ciphertext, data)?;
+        raw_tag.copy_from_slice(tag.as_bytes());
+        Ok(data.len() + TAG_LEN)
+    }
+
+    pub fn decrypt_detached(
+        &mut self,
+        out: &mut [u8],
+        data: &[u8],
+        raw_tag: &[u8],
+    ) -> Result<usize, CryptoError> {
+        if out.len() != data.len() {
+            bail!(CryptoError::InvalidLength);
+        }
+        let key = self.less_safe_key()?;
+        let nonce = aead::Nonce::assume_unique_for_key(self.next_nonce());
+        let mut in_out = data.to_vec();
+        in_out.extend_from_slice(raw_tag);
+        let plaintext = key
+            .open_in_place(nonce, aead::Aad::empty(), &mut in_out)
+            .map_err(|_| CryptoError::VerificationFailed)?;
+        out.copy_from_slice(plaintext);
+        Ok(plaintext.len())
+    }
+
+    pub fn decrypt(&mut self, out: &mut [u8], data: &[u8]) -> Result<usize, CryptoError> {
+        if data.len() < TAG_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let (ciphertext, raw_tag) = data.split_at(data.len() - TAG_LEN);
+        self.decrypt_detached(out, ciphertext, raw_tag)
+    }
+}
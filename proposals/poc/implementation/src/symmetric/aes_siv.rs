@@ -0,0 +1,109 @@
+use super::key::SymmetricKey;
+use super::tag::SymmetricTag;
+use super::{SymmetricAlgorithm, SymmetricOptions};
+use crate::error::*;
+use aes_gcm_siv::aead::{AeadInPlace, KeyInit};
+use aes_gcm_siv::{Aes128GcmSiv, Aes256GcmSiv, Nonce, Tag};
+
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+/// RFC 8452 AES-GCM-SIV: nonce-misuse-resistant, so a repeated nonce only
+/// reveals whether two messages were identical rather than breaking
+/// confidentiality the way plain AES-GCM does.
+enum Cipher {
+    Aes128(Aes128GcmSiv),
+    Aes256(Aes256GcmSiv),
+}
+
+pub struct AesSivState {
+    cipher: Cipher,
+    nonce: [u8; NONCE_LEN],
+}
+
+impl AesSivState {
+    pub fn new(key: &SymmetricKey, options: &SymmetricOptions) -> Result<Self, CryptoError> {
+        let raw_nonce = options.get("nonce").map_err(|_| CryptoError::NonceRequired)?;
+        if raw_nonce.len() != NONCE_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce.copy_from_slice(&raw_nonce);
+        let cipher = match key.alg() {
+            SymmetricAlgorithm::Aes128GcmSiv => Cipher::Aes128(
+                Aes128GcmSiv::new_from_slice(key.as_bytes()).map_err(|_| CryptoError::InvalidKey)?,
+            ),
+            SymmetricAlgorithm::Aes256GcmSiv => Cipher::Aes256(
+                Aes256GcmSiv::new_from_slice(key.as_bytes()).map_err(|_| CryptoError::InvalidKey)?,
+            ),
+            _ => bail!(CryptoError::UnsupportedAlgorithm),
+        };
+        Ok(AesSivState { cipher, nonce })
+    }
+
+    pub fn options_get(&self, name: &str) -> Result<Vec<u8>, CryptoError> {
+        match name.to_lowercase().as_str() {
+            "nonce" => Ok(self.nonce.to_vec()),
+            _ => bail!(CryptoError::UnsupportedOption),
+        }
+    }
+
+    pub fn max_tag_len(&self) -> Result<usize, CryptoError> {
+        Ok(TAG_LEN)
+    }
+
+    pub fn encrypt_detached(&mut self, out: &mut [u8], data: &[u8]) -> Result<SymmetricTag, CryptoError> {
+        if out.len() != data.len() {
+            bail!(CryptoError::InvalidLength);
+        }
+        out.copy_from_slice(data);
+        let nonce = Nonce::from_slice(&self.nonce);
+        let tag = match &self.cipher {
+            Cipher::Aes128(cipher) => cipher.encrypt_in_place_detached(nonce, b"", out),
+            Cipher::Aes256(cipher) => cipher.encrypt_in_place_detached(nonce, b"", out),
+        }
+        .map_err(|_| CryptoError::AlgorithmFailure)?;
+        Ok(SymmetricTag::new(tag.as_slice().to_vec()))
+    }
+
+    pub fn encrypt(&mut self, out: &mut [u8], data: &[u8]) -> Result<usize, CryptoError> {
+        if out.len() != data.len() + TAG_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let (ciphertext, raw_tag) = out.split_at_mut(data.len());
+        let tag = self.encrypt_detached(ciphertext, data)?;
+        raw_tag.copy_from_slice(tag.as_bytes());
+        Ok(data.len() + TAG_LEN)
+    }
+
+    pub fn decrypt_detached(
+        &mut self,
+        out: &mut [u8],
+        data: &[u8],
+        raw_tag: &[u8],
+    ) -> Result<usize, CryptoError> {
+        if out.len() != data.len() {
+            bail!(CryptoError::InvalidLength);
+        }
+        if raw_tag.len() != TAG_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        out.copy_from_slice(data);
+        let nonce = Nonce::from_slice(&self.nonce);
+        let tag = Tag::from_slice(raw_tag);
+        match &self.cipher {
+            Cipher::Aes128(cipher) => cipher.decrypt_in_place_detached(nonce, b"", out, tag),
+            Cipher::Aes256(cipher) => cipher.decrypt_in_place_detached(nonce, b"", out, tag),
+        }
+        .map_err(|_| CryptoError::VerificationFailed)?;
+        Ok(out.len())
+    }
+
+    pub fn decrypt(&mut self, out: &mut [u8], data: &[u8]) -> Result<usize, CryptoError> {
+        if data.len() < TAG_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let (ciphertext, raw_tag) = data.split_at(data.len() - TAG_LEN);
+        self.decrypt_detached(out, ciphertext, raw_tag)
+    }
+}
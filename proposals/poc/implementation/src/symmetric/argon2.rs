@@ -0,0 +1,54 @@
+use super::{SymmetricAlgorithm, SymmetricOptions};
+use crate::error::*;
+use argon2::{Algorithm, Argon2, Params, Version};
+
+const SALT_LEN: usize = 16;
+const DEFAULT_MEMORY_LIMIT: u64 = 64 * 1024 * 1024;
+const DEFAULT_OPS_LIMIT: u64 = 3;
+const DEFAULT_PARALLELISM: u64 = 1;
+
+pub struct Argon2State {
+    argon2: Argon2<'static>,
+    salt: Vec<u8>,
+    password: Vec<u8>,
+}
+
+impl Argon2State {
+    pub fn new(alg: SymmetricAlgorithm, options: &SymmetricOptions) -> Result<Self, CryptoError> {
+        let algorithm = match alg {
+            SymmetricAlgorithm::Argon2id => Algorithm::Argon2id,
+            SymmetricAlgorithm::Argon2i => Algorithm::Argon2i,
+            _ => bail!(CryptoError::UnsupportedAlgorithm),
+        };
+        let salt = options.get("salt").map_err(|_| CryptoError::OptionNotSet)?;
+        if salt.len() != SALT_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let memory_limit = options.get_u64("memory_limit").unwrap_or(DEFAULT_MEMORY_LIMIT);
+        let ops_limit = options.get_u64("ops_limit").unwrap_or(DEFAULT_OPS_LIMIT);
+        let parallelism = options.get_u64("parallelism").unwrap_or(DEFAULT_PARALLELISM);
+        let params = Params::new(
+            (memory_limit / 1024) as u32,
+            ops_limit as u32,
+            parallelism as u32,
+            None,
+        )
+        .map_err(|_| CryptoError::UnsupportedOption)?;
+        Ok(Argon2State {
+            argon2: Argon2::new(algorithm, Version::V0x13, params),
+            salt,
+            password: Vec::new(),
+        })
+    }
+
+    pub fn absorb(&mut self, data: &[u8]) -> Result<(), CryptoError> {
+        self.password.extend_from_slice(data);
+        Ok(())
+    }
+
+    pub fn squeeze(self, out: &mut [u8]) -> Result<(), CryptoError> {
+        self.argon2
+            .hash_password_into(&self.password, &self.salt, out)
+            .map_err(|_| CryptoError::AlgorithmFailure)
+    }
+}
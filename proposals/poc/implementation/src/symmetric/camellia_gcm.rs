@@ -0,0 +1,111 @@
+use super::key::SymmetricKey;
+use super::tag::SymmetricTag;
+use super::{SymmetricAlgorithm, SymmetricOptions};
+use crate::error::*;
+use aes_gcm::aead::consts::U12;
+use aes_gcm::aead::{AeadInPlace, KeyInit};
+use aes_gcm::{AesGcm, Nonce};
+use camellia::{Camellia128, Camellia256};
+
+type Camellia128Gcm = AesGcm<Camellia128, U12>;
+type Camellia256Gcm = AesGcm<Camellia256, U12>;
+
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+enum Cipher {
+    Camellia128(Camellia128Gcm),
+    Camellia256(Camellia256Gcm),
+}
+
+pub struct CamelliaGcmState {
+    cipher: Cipher,
+    nonce: [u8; NONCE_LEN],
+}
+
+impl CamelliaGcmState {
+    pub fn new(key: &SymmetricKey, options: &SymmetricOptions) -> Result<Self, CryptoError> {
+        let raw_nonce = options.get("nonce").map_err(|_| CryptoError::NonceRequired)?;
+        if raw_nonce.len() != NONCE_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce.copy_from_slice(&raw_nonce);
+        let cipher = match key.alg() {
+            SymmetricAlgorithm::Camellia128Gcm => Cipher::Camellia128(
+                Camellia128Gcm::new_from_slice(key.as_bytes()).map_err(|_| CryptoError::InvalidKey)?,
+            ),
+            SymmetricAlgorithm::Camellia256Gcm => Cipher::Camellia256(
+                Camellia256Gcm::new_from_slice(key.as_bytes()).map_err(|_| CryptoError::InvalidKey)?,
+            ),
+            _ => bail!(CryptoError::UnsupportedAlgorithm),
+        };
+        Ok(CamelliaGcmState { cipher, nonce })
+    }
+
+    pub fn options_get(&self, name: &str) -> Result<Vec<u8>, CryptoError> {
+        match name.to_lowercase().as_str() {
+            "nonce" => Ok(self.nonce.to_vec()),
+            _ => bail!(CryptoError::UnsupportedOption),
+        }
+    }
+
+    pub fn max_tag_len(&self) -> Result<usize, CryptoError> {
+        Ok(TAG_LEN)
+    }
+
+    pub fn encrypt_detached(&mut self, out: &mut [u8], data: &[u8]) -> Result<SymmetricTag, CryptoError> {
+        if out.len() != data.len() {
+            bail!(CryptoError::InvalidLength);
+        }
+        out.copy_from_slice(data);
+        let nonce = Nonce::from_slice(&self.nonce);
+        let tag = match &self.cipher {
+            Cipher::Camellia128(cipher) => cipher.encrypt_in_place_detached(nonce, b"", out),
+            Cipher::Camellia256(cipher) => cipher.encrypt_in_place_detached(nonce, b"", out),
+        }
+        .map_err(|_| CryptoError::AlgorithmFailure)?;
+        Ok(SymmetricTag::new(tag.as_slice().to_vec()))
+    }
+
+    pub fn encrypt(&mut self, out: &mut [u8], data: &[u8]) -> Result<usize, CryptoError> {
+        if out.len() != data.len() + TAG_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let (ciphertext, raw_tag) = out.split_at_mut(data.len());
+        let tag = self.encrypt_detached(ciphertext, data)?;
+        raw_tag.copy_from_slice(tag.as_bytes());
+        Ok(data.len() + TAG_LEN)
+    }
+
+    pub fn decrypt_detached(
+        &mut self,
+        out: &mut [u8],
+        data: &[u8],
+        raw_tag: &[u8],
+    ) -> Result<usize, CryptoError> {
+        if out.len() != data.len() {
+            bail!(CryptoError::InvalidLength);
+        }
+        if raw_tag.len() != TAG_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        out.copy_from_slice(data);
+        let nonce = Nonce::from_slice(&self.nonce);
+        let tag = aes_gcm::Tag::from_slice(raw_tag);
+        match &self.cipher {
+            Cipher::Camellia128(cipher) => cipher.decrypt_in_place_detached(nonce, b"", out, tag),
+            Cipher::Camellia256(cipher) => cipher.decrypt_in_place_detached(nonce, b"", out, tag),
+        }
+        .map_err(|_| CryptoError::VerificationFailed)?;
+        Ok(out.len())
+    }
+
+    pub fn decrypt(&mut self, out: &mut [u8], data: &[u8]) -> Result<usize, CryptoError> {
+        if data.len() < TAG_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let (ciphertext, raw_tag) = data.split_at(data.len() - TAG_LEN);
+        self.decrypt_detached(out, ciphertext, raw_tag)
+    }
+}
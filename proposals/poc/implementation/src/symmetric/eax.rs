@@ -0,0 +1,123 @@
+use super::key::SymmetricKey;
+use super::tag::{is_equal, SymmetricTag};
+use super::{SymmetricAlgorithm, SymmetricOptions};
+use crate::error::*;
+use aes::Aes256;
+use cmac::{Cmac, Mac};
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use ctr::Ctr64BE;
+
+const BLOCK_LEN: usize = 16;
+const TAG_LEN: usize = 16;
+
+/// `OMAC_t(data) = CMAC(key, [0; BLOCK_LEN - 1] || t || data)`, i.e. CMAC
+/// with the tag/tweak folded into the first block, as defined by EAX.
+fn omac(key: &[u8], t: u8, data: &[u8]) -> Result<[u8; BLOCK_LEN], CryptoError> {
+    let mut mac = Cmac::<Aes256>::new_from_slice(key).map_err(|_| CryptoError::InvalidKey)?;
+    let mut tweak = [0u8; BLOCK_LEN];
+    tweak[BLOCK_LEN - 1] = t;
+    mac.update(&tweak);
+    mac.update(data);
+    let mut out = [0u8; BLOCK_LEN];
+    out.copy_from_slice(&mac.finalize().into_bytes());
+    Ok(out)
+}
+
+fn xor_tags(n: &[u8; BLOCK_LEN], h: &[u8; BLOCK_LEN], c: &[u8; BLOCK_LEN]) -> [u8; TAG_LEN] {
+    let mut tag = [0u8; TAG_LEN];
+    for i in 0..TAG_LEN {
+        tag[i] = n[i] ^ h[i] ^ c[i];
+    }
+    tag
+}
+
+/// AES-256-EAX: CTR-mode encryption plus three tweaked OMACs (CMAC) of the
+/// nonce, the associated data (always empty here) and the ciphertext, XORed
+/// into a single tag. Unlike AES-GCM, the
+/// nonce may be any length since it is itself run through an OMAC.
+pub struct EaxState {
+    key: SymmetricKey,
+    nonce: Vec<u8>,
+}
+
+impl EaxState {
+    pub fn new(key: &SymmetricKey, options: &SymmetricOptions) -> Result<Self, CryptoError> {
+        match key.alg() {
+            SymmetricAlgorithm::Aes256Eax => {}
+            _ => bail!(CryptoError::UnsupportedAlgorithm),
+        }
+        let nonce = options.get("nonce").map_err(|_| CryptoError::NonceRequired)?;
+        Ok(EaxState {
+            key: key.clone(),
+            nonce,
+        })
+    }
+
+    pub fn options_get(&self, name: &str) -> Result<Vec<u8>, CryptoError> {
+        match name.to_lowercase().as_str() {
+            "nonce" => Ok(self.nonce.clone()),
+            _ => bail!(CryptoError::UnsupportedOption),
+        }
+    }
+
+    pub fn max_tag_len(&self) -> Result<usize, CryptoError> {
+        Ok(TAG_LEN)
+    }
+
+    fn apply_keystream(&self, counter_block: &[u8; BLOCK_LEN], buf: &mut [u8]) -> Result<(), CryptoError> {
+        let mut cipher = Ctr64BE::<Aes256>::new(self.key.as_bytes().into(), counter_block.into());
+        cipher.apply_keystream(buf);
+        Ok(())
+    }
+
+    pub fn encrypt_detached(&mut self, out: &mut [u8], data: &[u8]) -> Result<SymmetricTag, CryptoError> {
+        if out.len() != data.len() {
+            bail!(CryptoError::InvalidLength);
+        }
+        let n = omac(self.key.as_bytes(), 0, &self.nonce)?;
+        let h = omac(self.key.as_bytes(), 1, b"")?;
+        out.copy_from_slice(data);
+        self.apply_keystream(&n, out)?;
+        let c = omac(self.key.as_bytes(), 2, out)?;
+        Ok(SymmetricTag::new(xor_tags(&n, &h, &c).to_vec()))
+    }
+
+    pub fn encrypt(&mut self, out: &mut [u8], data: &[u8]) -> Result<usize, CryptoError> {
+        if out.len() != data.len() + TAG_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let (ciphertext, raw_tag) = out.split_at_mut(data.len());
+        let tag = self.encrypt_detached(ciphertext, data)?;
+        raw_tag.copy_from_slice(tag.as_bytes());
+        Ok(data.len() + TAG_LEN)
+    }
+
+    pub fn decrypt_detached(
+        &mut self,
+        out: &mut [u8],
+        data: &[u8],
+        raw_tag: &[u8],
+    ) -> Result<usize, CryptoError> {
+        if out.len() != data.len() || raw_tag.len() != TAG_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let n = omac(self.key.as_bytes(), 0, &self.nonce)?;
+        let h = omac(self.key.as_bytes(), 1, b"")?;
+        let c = omac(self.key.as_bytes(), 2, data)?;
+        let expected = xor_tags(&n, &h, &c);
+        if !is_equal(&expected, raw_tag) {
+            bail!(CryptoError::VerificationFailed);
+        }
+        out.copy_from_slice(data);
+        self.apply_keystream(&n, out)?;
+        Ok(out.len())
+    }
+
+    pub fn decrypt(&mut self, out: &mut [u8], data: &[u8]) -> Result<usize, CryptoError> {
+        if data.len() < TAG_LEN {
+            bail!(CryptoError::InvalidLength);
+        }
+        let (ciphertext, raw_tag) = data.split_at(data.len() - TAG_LEN);
+        self.decrypt_detached(out, ciphertext, raw_tag)
+    }
+}
@@ -0,0 +1,104 @@
+use super::key::SymmetricKey;
+use super::{SymmetricAlgorithm, SymmetricOptions};
+use crate::error::*;
+use ring::hmac;
+
+fn algorithm(alg: SymmetricAlgorithm) -> Result<hmac::Algorithm, CryptoError> {
+    match alg {
+        SymmetricAlgorithm::HkdfSha256Extract | SymmetricAlgorithm::HkdfSha256Expand => {
+            Ok(hmac::HMAC_SHA256)
+        }
+        SymmetricAlgorithm::HkdfSha512Extract | SymmetricAlgorithm::HkdfSha512Expand => {
+            Ok(hmac::HMAC_SHA512)
+        }
+        _ => bail!(CryptoError::UnsupportedAlgorithm),
+    }
+}
+
+fn hash_len(alg: SymmetricAlgorithm) -> Result<usize, CryptoError> {
+    match alg {
+        SymmetricAlgorithm::HkdfSha256Extract | SymmetricAlgorithm::HkdfSha256Expand => Ok(32),
+        SymmetricAlgorithm::HkdfSha512Extract | SymmetricAlgorithm::HkdfSha512Expand => Ok(64),
+        _ => bail!(CryptoError::UnsupportedAlgorithm),
+    }
+}
+
+pub struct HkdfExtractState {
+    alg: SymmetricAlgorithm,
+    salt: Vec<u8>,
+    ikm: Vec<u8>,
+}
+
+impl HkdfExtractState {
+    pub fn new(alg: SymmetricAlgorithm, options: &SymmetricOptions) -> Result<Self, CryptoError> {
+        let salt = options
+            .get("salt")
+            .unwrap_or_else(|_| vec![0u8; hash_len(alg).unwrap_or(0)]);
+        Ok(HkdfExtractState {
+            alg,
+            salt,
+            ikm: Vec::new(),
+        })
+    }
+
+    pub fn absorb(&mut self, data: &[u8]) -> Result<(), CryptoError> {
+        self.ikm.extend_from_slice(data);
+        Ok(())
+    }
+
+    pub fn squeeze_key(self, alg: SymmetricAlgorithm) -> Result<SymmetricKey, CryptoError> {
+        if hash_len(alg)? != hash_len(self.alg)? {
+            bail!(CryptoError::UnsupportedAlgorithm);
+        }
+        let key = hmac::Key::new(algorithm(self.alg)?, &self.salt);
+        let prk = hmac::sign(&key, &self.ikm);
+        Ok(SymmetricKey::new(alg, prk.as_ref().to_vec()))
+    }
+}
+
+pub struct HkdfExpandState {
+    alg: SymmetricAlgorithm,
+    prk: SymmetricKey,
+    info: Vec<u8>,
+}
+
+impl HkdfExpandState {
+    pub fn new(alg: SymmetricAlgorithm, prk: &SymmetricKey) -> Result<Self, CryptoError> {
+        if hash_len(prk.alg())? != hash_len(alg)? {
+            bail!(CryptoError::UnsupportedAlgorithm);
+        }
+        Ok(HkdfExpandState {
+            alg,
+            prk: prk.clone(),
+            info: Vec::new(),
+        })
+    }
+
+    pub fn absorb(&mut self, data: &[u8]) -> Result<(), CryptoError> {
+        self.info.extend_from_slice(data);
+        Ok(())
+    }
+
+    pub fn squeeze(self, out: &mut [u8]) -> Result<(), CryptoError> {
+        let hash_len = hash_len(self.alg)?;
+        if out.len() > 255 * hash_len {
+            bail!(CryptoError::InvalidLength);
+        }
+        let key = hmac::Key::new(algorithm(self.alg)?, self.prk.as_bytes());
+        let mut t: Vec<u8> = Vec::new();
+        let mut counter: u8 = 0;
+        let mut written = 0;
+        while written < out.len() {
+            counter += 1;
+            let mut ctx = hmac::Context::with_key(&key);
+            ctx.update(&t);
+            ctx.update(&self.info);
+            ctx.update(&[counter]);
+            t = ctx.sign().as_ref().to_vec();
+            let n = std::cmp::min(t.len(), out.len() - written);
+            out[written..written + n].copy_from_slice(&t[..n]);
+            written += n;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use super::tag::SymmetricTag;
+use super::SymmetricAlgorithm;
+use crate::error::*;
+use ring::hmac;
+
+pub struct HmacSha2State {
+    ctx: hmac::Context,
+}
+
+impl HmacSha2State {
+    pub fn new(alg: SymmetricAlgorithm, raw_key: &[u8]) -> Result<Self, CryptoError> {
+        let algorithm = match alg {
+            SymmetricAlgorithm::HmacSha256 => hmac::HMAC_SHA256,
+            SymmetricAlgorithm::HmacSha512 => hmac::HMAC_SHA512,
+            _ => bail!(CryptoError::UnsupportedAlgorithm),
+        };
+        let key = hmac::Key::new(algorithm, raw_key);
+        Ok(HmacSha2State {
+            ctx: hmac::Context::with_key(&key),
+        })
+    }
+
+    pub fn absorb(&mut self, data: &[u8]) -> Result<(), CryptoError> {
+        self.ctx.update(data);
+        Ok(())
+    }
+
+    /// Non-destructive: finalizes a clone of the running context, so the
+    /// state stays usable for further `absorb`/`squeeze_tag` calls, matching
+    /// the guest-visible semantics of repeatedly tagging the same stream.
+    pub fn squeeze_tag(&self) -> Result<SymmetricTag, CryptoError> {
+        let tag = self.ctx.clone().sign();
+        Ok(SymmetricTag::new(tag.as_ref().to_vec()))
+    }
+}
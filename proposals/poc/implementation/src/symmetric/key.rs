@@ -0,0 +1,29 @@
+use super::SymmetricAlgorithm;
+
+#[derive(Clone, Debug)]
+pub struct SymmetricKey {
+    alg: SymmetricAlgorithm,
+    raw: Vec<u8>,
+}
+
+impl SymmetricKey {
+    pub fn new(alg: SymmetricAlgorithm, raw: Vec<u8>) -> Self {
+        SymmetricKey { alg, raw }
+    }
+
+    pub fn alg(&self) -> SymmetricAlgorithm {
+        self.alg
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.raw
+    }
+
+    pub fn len(&self) -> usize {
+        self.raw.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.raw.is_empty()
+    }
+}
@@ -0,0 +1,34 @@
+use super::key::SymmetricKey;
+use super::SymmetricAlgorithm;
+use crate::error::*;
+use ring::rand::{SecureRandom, SystemRandom};
+
+fn raw_key_len(alg: SymmetricAlgorithm) -> Result<usize, CryptoError> {
+    match alg {
+        SymmetricAlgorithm::HmacSha256 => Ok(32),
+        SymmetricAlgorithm::HmacSha512 => Ok(64),
+        SymmetricAlgorithm::Aes128Gcm => Ok(16),
+        SymmetricAlgorithm::Aes256Gcm => Ok(32),
+        SymmetricAlgorithm::Aes128GcmSiv => Ok(16),
+        SymmetricAlgorithm::Aes256GcmSiv => Ok(32),
+        SymmetricAlgorithm::Aes256Eax => Ok(32),
+        SymmetricAlgorithm::Camellia128Gcm => Ok(16),
+        SymmetricAlgorithm::Camellia256Gcm => Ok(32),
+        _ => bail!(CryptoError::KeyNotSupported),
+    }
+}
+
+pub fn generate(alg: SymmetricAlgorithm) -> Result<SymmetricKey, CryptoError> {
+    let mut raw = vec![0u8; raw_key_len(alg)?];
+    SystemRandom::new()
+        .fill(&mut raw)
+        .map_err(|_| CryptoError::RngError)?;
+    Ok(SymmetricKey::new(alg, raw))
+}
+
+pub fn import(alg: SymmetricAlgorithm, raw: &[u8]) -> Result<SymmetricKey, CryptoError> {
+    if raw.len() != raw_key_len(alg)? {
+        bail!(CryptoError::InvalidKey);
+    }
+    Ok(SymmetricKey::new(alg, raw.to_vec()))
+}
@@ -1,7 +1,13 @@
 mod aes_gcm;
+mod aes_siv;
+mod argon2;
+mod camellia_gcm;
+mod eax;
+mod hkdf;
 mod hmac_sha2;
 mod key;
 mod key_manager;
+mod noise;
 mod sha2;
 mod state;
 mod tag;
@@ -10,6 +16,11 @@ use crate::error::*;
 use crate::handles::*;
 use crate::options::*;
 use aes_gcm::*;
+use aes_siv::*;
+use argon2::*;
+use camellia_gcm::*;
+use eax::*;
+use hkdf::*;
 use hmac_sha2::*;
 use parking_lot::Mutex;
 use sha2::*;
@@ -20,7 +31,7 @@ use std::sync::Arc;
 pub use key::SymmetricKey;
 pub use key_manager::*;
 pub use state::SymmetricState;
-pub use tag::SymmetricTag;
+pub use tag::{is_equal, SymmetricTag};
 
 #[derive(Debug, Default)]
 pub struct SymmetricOptionsInner {
@@ -107,6 +118,17 @@ pub enum SymmetricAlgorithm {
     Sha512_256,
     Aes128Gcm,
     Aes256Gcm,
+    Aes128GcmSiv,
+    Aes256GcmSiv,
+    Aes256Eax,
+    Camellia128Gcm,
+    Camellia256Gcm,
+    Argon2id,
+    Argon2i,
+    HkdfSha256Extract,
+    HkdfSha256Expand,
+    HkdfSha512Extract,
+    HkdfSha512Expand,
 }
 
 impl TryFrom<&str> for SymmetricAlgorithm {
@@ -121,6 +143,17 @@ impl TryFrom<&str> for SymmetricAlgorithm {
             "SHA-512/256" => Ok(SymmetricAlgorithm::Sha512_256),
             "AES-128-GCM" => Ok(SymmetricAlgorithm::Aes128Gcm),
             "AES-256-GCM" => Ok(SymmetricAlgorithm::Aes256Gcm),
+            "AES-128-GCM-SIV" => Ok(SymmetricAlgorithm::Aes128GcmSiv),
+            "AES-256-GCM-SIV" => Ok(SymmetricAlgorithm::Aes256GcmSiv),
+            "AES-256-EAX" => Ok(SymmetricAlgorithm::Aes256Eax),
+            "CAMELLIA-128-GCM" => Ok(SymmetricAlgorithm::Camellia128Gcm),
+            "CAMELLIA-256-GCM" => Ok(SymmetricAlgorithm::Camellia256Gcm),
+            "ARGON2ID" => Ok(SymmetricAlgorithm::Argon2id),
+            "ARGON2I" => Ok(SymmetricAlgorithm::Argon2i),
+            "HKDF-EXTRACT/SHA-256" => Ok(SymmetricAlgorithm::HkdfSha256Extract),
+            "HKDF-EXPAND/SHA-256" => Ok(SymmetricAlgorithm::HkdfSha256Expand),
+            "HKDF-EXTRACT/SHA-512" => Ok(SymmetricAlgorithm::HkdfSha512Extract),
+            "HKDF-EXPAND/SHA-512" => Ok(SymmetricAlgorithm::HkdfSha512Expand),
             _ => bail!(CryptoError::UnsupportedAlgorithm),
         }
     }
@@ -228,6 +261,235 @@ fn test_encryption() {
     assert_eq!(msg, &msg2[..]);
 }
 
+#[test]
+fn test_argon2id() {
+    use crate::CryptoCtx;
+
+    let ctx = CryptoCtx::new();
+
+    let options_handle = ctx.options_open(OptionsType::Symmetric).unwrap();
+    ctx.options_set(options_handle, "salt", &[0u8; 16]).unwrap();
+
+    let state_handle = ctx
+        .symmetric_state_open("ARGON2ID", None, Some(options_handle))
+        .unwrap();
+    ctx.symmetric_state_absorb(state_handle, b"password").unwrap();
+    let mut out = [0u8; 32];
+    ctx.symmetric_state_squeeze(state_handle, &mut out).unwrap();
+    assert_ne!(out, [0u8; 32]);
+    ctx.symmetric_state_close(state_handle).unwrap();
+}
+
+#[test]
+fn test_hkdf() {
+    use crate::CryptoCtx;
+
+    let ctx = CryptoCtx::new();
+
+    let options_handle = ctx.options_open(OptionsType::Symmetric).unwrap();
+    ctx.options_set(options_handle, "salt", &[0u8; 32]).unwrap();
+
+    let extract_handle = ctx
+        .symmetric_state_open("HKDF-EXTRACT/SHA-256", None, Some(options_handle))
+        .unwrap();
+    ctx.symmetric_state_absorb(extract_handle, b"input-key-material")
+        .unwrap();
+    let prk_handle = ctx
+        .symmetric_state_squeeze_key(extract_handle, "HKDF-EXPAND/SHA-256")
+        .unwrap();
+    ctx.symmetric_state_close(extract_handle).unwrap();
+
+    let expand_handle = ctx
+        .symmetric_state_open("HKDF-EXPAND/SHA-256", Some(prk_handle), None)
+        .unwrap();
+    ctx.symmetric_state_absorb(expand_handle, b"context-info")
+        .unwrap();
+    let mut okm = [0u8; 42];
+    ctx.symmetric_state_squeeze(expand_handle, &mut okm).unwrap();
+    assert_ne!(okm, [0u8; 42]);
+    ctx.symmetric_state_close(expand_handle).unwrap();
+    ctx.symmetric_key_close(prk_handle).unwrap();
+}
+
+#[test]
+fn test_noise_handshake() {
+    use crate::CryptoCtx;
+
+    let ctx = CryptoCtx::new();
+
+    // A plain, keyless hash still behaves as a standard streaming digest.
+    let plain_handle = ctx.symmetric_state_open("SHA-256", None, None).unwrap();
+    ctx.symmetric_state_absorb(plain_handle, b"data").unwrap();
+    ctx.symmetric_state_absorb(plain_handle, b"more_data")
+        .unwrap();
+    let mut plain_out = [0u8; 32];
+    ctx.symmetric_state_squeeze(plain_handle, &mut plain_out)
+        .unwrap();
+    ctx.symmetric_state_close(plain_handle).unwrap();
+
+    // Supplying a key instead switches the state into Noise transcript mode,
+    // where `absorb` mixes into the transcript hash and `ratchet` folds
+    // separately-supplied key material into the chaining key.
+    let key_handle = ctx.symmetric_key_generate("HMAC/SHA-256", None).unwrap();
+    let state_handle = ctx
+        .symmetric_state_open("SHA-256", Some(key_handle), None)
+        .unwrap();
+    ctx.symmetric_state_absorb(state_handle, b"handshake-data")
+        .unwrap();
+    ctx.symmetric_state_ratchet(state_handle, b"dh-output")
+        .unwrap();
+
+    // The peer runs the same handshake and so derives the same key pair.
+    let peer_handle = ctx
+        .symmetric_state_open("SHA-256", Some(key_handle), None)
+        .unwrap();
+    ctx.symmetric_state_absorb(peer_handle, b"handshake-data")
+        .unwrap();
+    ctx.symmetric_state_ratchet(peer_handle, b"dh-output")
+        .unwrap();
+
+    let (state1, state2) = ctx.symmetric_state_split(state_handle).unwrap();
+    let (peer1, peer2) = ctx.symmetric_state_split(peer_handle).unwrap();
+    ctx.symmetric_state_close(state_handle).unwrap();
+    ctx.symmetric_state_close(peer_handle).unwrap();
+    ctx.symmetric_key_close(key_handle).unwrap();
+
+    let msg = b"transport message";
+    let mut ciphertext =
+        vec![0u8; msg.len() + ctx.symmetric_state_max_tag_len(state1).unwrap()];
+    ctx.symmetric_state_encrypt(state1, &mut ciphertext, msg)
+        .unwrap();
+
+    // The first split state of each side shares a key; the second direction
+    // uses a different key and cannot open it.
+    let mut decrypted = vec![0u8; msg.len()];
+    ctx.symmetric_state_decrypt(peer1, &mut decrypted, &ciphertext)
+        .unwrap();
+    assert_eq!(msg, &decrypted[..]);
+    assert!(ctx
+        .symmetric_state_decrypt(state2, &mut decrypted, &ciphertext)
+        .is_err());
+
+    ctx.symmetric_state_close(state1).unwrap();
+    ctx.symmetric_state_close(state2).unwrap();
+    ctx.symmetric_state_close(peer1).unwrap();
+    ctx.symmetric_state_close(peer2).unwrap();
+}
+
+#[test]
+fn test_aes_gcm_siv() {
+    use crate::CryptoCtx;
+
+    let ctx = CryptoCtx::new();
+
+    let msg = b"nonce reuse is safer here";
+    let nonce = [7u8; 12];
+    let key_handle = ctx.symmetric_key_generate("AES-256-GCM-SIV", None).unwrap();
+
+    let options_handle = ctx.options_open(OptionsType::Symmetric).unwrap();
+    ctx.options_set(options_handle, "nonce", &nonce).unwrap();
+
+    let state_handle = ctx
+        .symmetric_state_open("AES-256-GCM-SIV", Some(key_handle), Some(options_handle))
+        .unwrap();
+    let mut ciphertext =
+        vec![0u8; msg.len() + ctx.symmetric_state_max_tag_len(state_handle).unwrap()];
+    ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, msg)
+        .unwrap();
+    ctx.symmetric_state_close(state_handle).unwrap();
+
+    let state_handle = ctx
+        .symmetric_state_open("AES-256-GCM-SIV", Some(key_handle), Some(options_handle))
+        .unwrap();
+    let mut decrypted = vec![0u8; msg.len()];
+    ctx.symmetric_state_decrypt(state_handle, &mut decrypted, &ciphertext)
+        .unwrap();
+    assert_eq!(msg, &decrypted[..]);
+    ctx.symmetric_state_close(state_handle).unwrap();
+
+    // A missing nonce must be rejected rather than silently defaulting to an
+    // all-zero one.
+    assert!(ctx
+        .symmetric_state_open("AES-256-GCM-SIV", Some(key_handle), None)
+        .is_err());
+
+    ctx.symmetric_key_close(key_handle).unwrap();
+}
+
+#[test]
+fn test_camellia_gcm_and_eax() {
+    use crate::CryptoCtx;
+
+    let ctx = CryptoCtx::new();
+    let msg = b"camellia and eax";
+
+    let nonce = [3u8; 12];
+    let key_handle = ctx.symmetric_key_generate("CAMELLIA-256-GCM", None).unwrap();
+    let options_handle = ctx.options_open(OptionsType::Symmetric).unwrap();
+    ctx.options_set(options_handle, "nonce", &nonce).unwrap();
+
+    let state_handle = ctx
+        .symmetric_state_open("CAMELLIA-256-GCM", Some(key_handle), Some(options_handle))
+        .unwrap();
+    let mut ciphertext =
+        vec![0u8; msg.len() + ctx.symmetric_state_max_tag_len(state_handle).unwrap()];
+    ctx.symmetric_state_encrypt(state_handle, &mut ciphertext, msg)
+        .unwrap();
+    ctx.symmetric_state_close(state_handle).unwrap();
+
+    let state_handle = ctx
+        .symmetric_state_open("CAMELLIA-256-GCM", Some(key_handle), Some(options_handle))
+        .unwrap();
+    let mut decrypted = vec![0u8; msg.len()];
+    ctx.symmetric_state_decrypt(state_handle, &mut decrypted, &ciphertext)
+        .unwrap();
+    assert_eq!(msg, &decrypted[..]);
+    ctx.symmetric_state_close(state_handle).unwrap();
+
+    // A malformed (too-short) tag must be rejected, not panic the host.
+    let bogus_state = ctx
+        .symmetric_state_open("CAMELLIA-256-GCM", Some(key_handle), Some(options_handle))
+        .unwrap();
+    assert!(ctx
+        .symmetric_state_decrypt_detached(bogus_state, &mut [0u8; 17], &ciphertext[..17], &[0u8; 4])
+        .is_err());
+    ctx.symmetric_state_close(bogus_state).unwrap();
+    ctx.symmetric_key_close(key_handle).unwrap();
+
+    let eax_nonce = b"an eax nonce of arbitrary length";
+    let eax_key_handle = ctx.symmetric_key_generate("AES-256-EAX", None).unwrap();
+    let eax_options_handle = ctx.options_open(OptionsType::Symmetric).unwrap();
+    ctx.options_set(eax_options_handle, "nonce", eax_nonce)
+        .unwrap();
+
+    let eax_state_handle = ctx
+        .symmetric_state_open("AES-256-EAX", Some(eax_key_handle), Some(eax_options_handle))
+        .unwrap();
+    let mut eax_ciphertext =
+        vec![0u8; msg.len() + ctx.symmetric_state_max_tag_len(eax_state_handle).unwrap()];
+    ctx.symmetric_state_encrypt(eax_state_handle, &mut eax_ciphertext, msg)
+        .unwrap();
+    ctx.symmetric_state_close(eax_state_handle).unwrap();
+
+    let eax_state_handle = ctx
+        .symmetric_state_open("AES-256-EAX", Some(eax_key_handle), Some(eax_options_handle))
+        .unwrap();
+    let mut eax_decrypted = vec![0u8; msg.len()];
+    ctx.symmetric_state_decrypt(eax_state_handle, &mut eax_decrypted, &eax_ciphertext)
+        .unwrap();
+    assert_eq!(msg, &eax_decrypted[..]);
+    ctx.symmetric_state_close(eax_state_handle).unwrap();
+
+    // A key minted for a different algorithm must not be usable with EAX.
+    let gcm_key_handle = ctx.symmetric_key_generate("AES-256-GCM", None).unwrap();
+    assert!(ctx
+        .symmetric_state_open("AES-256-EAX", Some(gcm_key_handle), Some(eax_options_handle))
+        .is_err());
+    ctx.symmetric_key_close(gcm_key_handle).unwrap();
+
+    ctx.symmetric_key_close(eax_key_handle).unwrap();
+}
+
 #[cfg(test)]
 fn tag_to_vec(ctx: &crate::CryptoCtx, symmetric_tag: Handle) -> Result<Vec<u8>, CryptoError> {
     let mut bytes = vec![0u8; ctx.symmetric_tag_len(symmetric_tag)?];
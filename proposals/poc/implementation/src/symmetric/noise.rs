@@ -0,0 +1,104 @@
+use super::sha2::Sha2State;
+use super::SymmetricAlgorithm;
+use crate::error::*;
+use ring::hmac;
+
+fn hmac_algorithm(alg: SymmetricAlgorithm) -> Result<hmac::Algorithm, CryptoError> {
+    match alg {
+        SymmetricAlgorithm::Sha256 => Ok(hmac::HMAC_SHA256),
+        SymmetricAlgorithm::Sha512 | SymmetricAlgorithm::Sha512_256 => Ok(hmac::HMAC_SHA512),
+        _ => bail!(CryptoError::UnsupportedAlgorithm),
+    }
+}
+
+fn hash_len(alg: SymmetricAlgorithm) -> Result<usize, CryptoError> {
+    match alg {
+        SymmetricAlgorithm::Sha256 => Ok(32),
+        SymmetricAlgorithm::Sha512 => Ok(64),
+        SymmetricAlgorithm::Sha512_256 => Ok(32),
+        _ => bail!(CryptoError::UnsupportedAlgorithm),
+    }
+}
+
+/// The Noise Protocol's `HKDF(chaining_key, input_key_material, num_outputs)`:
+/// a chain of HMACs keyed by a `temp_key` derived from `chaining_key`.
+fn noise_hkdf(
+    alg: SymmetricAlgorithm,
+    chaining_key: &[u8],
+    input_key_material: &[u8],
+    num_outputs: usize,
+) -> Result<Vec<Vec<u8>>, CryptoError> {
+    let algorithm = hmac_algorithm(alg)?;
+    let temp_key = hmac::sign(&hmac::Key::new(algorithm, chaining_key), input_key_material);
+    let temp_key = hmac::Key::new(algorithm, temp_key.as_ref());
+    let mut outputs = Vec::with_capacity(num_outputs);
+    let mut previous: Vec<u8> = Vec::new();
+    for i in 1..=num_outputs {
+        let mut ctx = hmac::Context::with_key(&temp_key);
+        ctx.update(&previous);
+        ctx.update(&[i as u8]);
+        previous = ctx.sign().as_ref().to_vec();
+        outputs.push(previous.clone());
+    }
+    Ok(outputs)
+}
+
+/// A Noise-style `SymmetricState`: a transcript hash `h` advanced by every
+/// `absorb` (MixHash: `h = HASH(h || data)`), plus a chaining key `ck`
+/// advanced by `ratchet` (MixKey) and consumed by `split`.
+///
+/// `absorb` and `ratchet` are deliberately separate: a Noise handshake step
+/// mixes transcript data (e.g. a public key) into `h` via `absorb`, and
+/// mixes independently-obtained key material (e.g. a DH output) into `ck`
+/// via `ratchet` — the two are not the same bytes.
+pub struct NoiseState {
+    alg: SymmetricAlgorithm,
+    h: Vec<u8>,
+    ck: Vec<u8>,
+}
+
+impl NoiseState {
+    pub fn new(alg: SymmetricAlgorithm, ck: Vec<u8>) -> Result<Self, CryptoError> {
+        let hash_len = hash_len(alg)?;
+        let mut h = vec![0u8; hash_len];
+        let n = std::cmp::min(h.len(), ck.len());
+        h[..n].copy_from_slice(&ck[..n]);
+        Ok(NoiseState { alg, h, ck })
+    }
+
+    /// MixHash: `h = HASH(h || data)`, re-hashing the running transcript
+    /// digest with the newly absorbed data rather than streaming into one
+    /// continuous digest context.
+    pub fn absorb(&mut self, data: &[u8]) -> Result<(), CryptoError> {
+        let mut ctx = Sha2State::new(self.alg)?;
+        ctx.absorb(&self.h)?;
+        ctx.absorb(data)?;
+        let mut h = vec![0u8; self.h.len()];
+        ctx.squeeze(&mut h)?;
+        self.h = h;
+        Ok(())
+    }
+
+    pub fn squeeze(&self, out: &mut [u8]) -> Result<(), CryptoError> {
+        if out.len() != self.h.len() {
+            bail!(CryptoError::InvalidLength);
+        }
+        out.copy_from_slice(&self.h);
+        Ok(())
+    }
+
+    /// MixKey: `(ck, temp_k) = HKDF(ck, ikm, 2)`. Only `ck` is kept here; the
+    /// transport keys come from the final `split`. `ikm` is caller-supplied
+    /// (e.g. a DH output) and is independent of any data passed to `absorb`.
+    pub fn ratchet(&mut self, ikm: &[u8]) -> Result<(), CryptoError> {
+        let outputs = noise_hkdf(self.alg, &self.ck, ikm, 2)?;
+        self.ck = outputs[0].clone();
+        Ok(())
+    }
+
+    /// `(k1, k2) = HKDF(ck, "", 2)`, one key per direction.
+    pub fn split(&self) -> Result<(Vec<u8>, Vec<u8>), CryptoError> {
+        let outputs = noise_hkdf(self.alg, &self.ck, b"", 2)?;
+        Ok((outputs[0].clone(), outputs[1].clone()))
+    }
+}
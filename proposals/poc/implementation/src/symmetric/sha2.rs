@@ -0,0 +1,35 @@
+use super::SymmetricAlgorithm;
+use crate::error::*;
+use ring::digest;
+
+pub struct Sha2State {
+    ctx: digest::Context,
+}
+
+impl Sha2State {
+    pub fn new(alg: SymmetricAlgorithm) -> Result<Self, CryptoError> {
+        let algorithm = match alg {
+            SymmetricAlgorithm::Sha256 => &digest::SHA256,
+            SymmetricAlgorithm::Sha512 => &digest::SHA512,
+            SymmetricAlgorithm::Sha512_256 => &digest::SHA512_256,
+            _ => bail!(CryptoError::UnsupportedAlgorithm),
+        };
+        Ok(Sha2State {
+            ctx: digest::Context::new(algorithm),
+        })
+    }
+
+    pub fn absorb(&mut self, data: &[u8]) -> Result<(), CryptoError> {
+        self.ctx.update(data);
+        Ok(())
+    }
+
+    pub fn squeeze(self, out: &mut [u8]) -> Result<(), CryptoError> {
+        let digest = self.ctx.finish();
+        if out.len() != digest.as_ref().len() {
+            bail!(CryptoError::InvalidLength);
+        }
+        out.copy_from_slice(digest.as_ref());
+        Ok(())
+    }
+}
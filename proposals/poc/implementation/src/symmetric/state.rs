@@ -0,0 +1,240 @@
+use super::aes_gcm::AesGcmState;
+use super::aes_siv::AesSivState;
+use super::argon2::Argon2State;
+use super::camellia_gcm::CamelliaGcmState;
+use super::eax::EaxState;
+use super::hkdf::{HkdfExpandState, HkdfExtractState};
+use super::hmac_sha2::HmacSha2State;
+use super::key::SymmetricKey;
+use super::noise::NoiseState;
+use super::sha2::Sha2State;
+use super::tag::SymmetricTag;
+use super::{SymmetricAlgorithm, SymmetricOptions};
+use crate::error::*;
+
+enum Inner {
+    Hash(Option<Sha2State>),
+    Noise(NoiseState),
+    Hmac(Option<HmacSha2State>),
+    AesGcm(AesGcmState),
+    AesSiv(AesSivState),
+    CamelliaGcm(CamelliaGcmState),
+    Eax(EaxState),
+    Argon2(Option<Argon2State>),
+    HkdfExtract(Option<HkdfExtractState>),
+    HkdfExpand(Option<HkdfExpandState>),
+}
+
+pub struct SymmetricState {
+    alg: SymmetricAlgorithm,
+    options: SymmetricOptions,
+    inner: Inner,
+}
+
+impl SymmetricState {
+    pub fn open(
+        alg: SymmetricAlgorithm,
+        key: Option<&SymmetricKey>,
+        options: Option<SymmetricOptions>,
+    ) -> Result<Self, CryptoError> {
+        let options = options.unwrap_or_default();
+        let inner = match alg {
+            // A bare hash (no key) is a plain streaming digest. Supplying a
+            // key seeds a Noise-style chaining key, switching the state into
+            // MixHash/MixKey transcript mode so `ratchet`/`split` work —
+            // these two uses need different math and must not share state.
+            SymmetricAlgorithm::Sha256 | SymmetricAlgorithm::Sha512 | SymmetricAlgorithm::Sha512_256 => {
+                match key {
+                    Some(key) => Inner::Noise(NoiseState::new(alg, key.as_bytes().to_vec())?),
+                    None => Inner::Hash(Some(Sha2State::new(alg)?)),
+                }
+            }
+            SymmetricAlgorithm::HmacSha256 | SymmetricAlgorithm::HmacSha512 => {
+                let key = key.ok_or(CryptoError::KeyRequired)?;
+                Inner::Hmac(Some(HmacSha2State::new(alg, key.as_bytes())?))
+            }
+            SymmetricAlgorithm::Aes128Gcm | SymmetricAlgorithm::Aes256Gcm => {
+                let key = key.ok_or(CryptoError::KeyRequired)?;
+                Inner::AesGcm(AesGcmState::new(key, &options)?)
+            }
+            SymmetricAlgorithm::Aes128GcmSiv | SymmetricAlgorithm::Aes256GcmSiv => {
+                let key = key.ok_or(CryptoError::KeyRequired)?;
+                Inner::AesSiv(AesSivState::new(key, &options)?)
+            }
+            SymmetricAlgorithm::Camellia128Gcm | SymmetricAlgorithm::Camellia256Gcm => {
+                let key = key.ok_or(CryptoError::KeyRequired)?;
+                Inner::CamelliaGcm(CamelliaGcmState::new(key, &options)?)
+            }
+            SymmetricAlgorithm::Aes256Eax => {
+                let key = key.ok_or(CryptoError::KeyRequired)?;
+                Inner::Eax(EaxState::new(key, &options)?)
+            }
+            SymmetricAlgorithm::Argon2id | SymmetricAlgorithm::Argon2i => {
+                Inner::Argon2(Some(Argon2State::new(alg, &options)?))
+            }
+            SymmetricAlgorithm::HkdfSha256Extract | SymmetricAlgorithm::HkdfSha512Extract => {
+                Inner::HkdfExtract(Some(HkdfExtractState::new(alg, &options)?))
+            }
+            SymmetricAlgorithm::HkdfSha256Expand | SymmetricAlgorithm::HkdfSha512Expand => {
+                let key = key.ok_or(CryptoError::KeyRequired)?;
+                Inner::HkdfExpand(Some(HkdfExpandState::new(alg, key)?))
+            }
+            SymmetricAlgorithm::None => bail!(CryptoError::UnsupportedAlgorithm),
+        };
+        Ok(SymmetricState { alg, options, inner })
+    }
+
+    pub fn alg(&self) -> SymmetricAlgorithm {
+        self.alg
+    }
+
+    pub fn options_get(&self, name: &str) -> Result<Vec<u8>, CryptoError> {
+        match &self.inner {
+            Inner::AesGcm(state) => state.options_get(name),
+            Inner::AesSiv(state) => state.options_get(name),
+            Inner::CamelliaGcm(state) => state.options_get(name),
+            Inner::Eax(state) => state.options_get(name),
+            _ => self.options.get(name),
+        }
+    }
+
+    pub fn max_tag_len(&self) -> Result<usize, CryptoError> {
+        match &self.inner {
+            Inner::AesGcm(state) => state.max_tag_len(),
+            Inner::AesSiv(state) => state.max_tag_len(),
+            Inner::CamelliaGcm(state) => state.max_tag_len(),
+            Inner::Eax(state) => state.max_tag_len(),
+            _ => bail!(CryptoError::UnsupportedOption),
+        }
+    }
+
+    pub fn absorb(&mut self, data: &[u8]) -> Result<(), CryptoError> {
+        match &mut self.inner {
+            Inner::Hash(state) => state.as_mut().ok_or(CryptoError::InvalidHandle)?.absorb(data),
+            Inner::Noise(state) => state.absorb(data),
+            Inner::Hmac(state) => state.as_mut().ok_or(CryptoError::InvalidHandle)?.absorb(data),
+            Inner::Argon2(state) => state.as_mut().ok_or(CryptoError::InvalidHandle)?.absorb(data),
+            Inner::HkdfExtract(state) => state.as_mut().ok_or(CryptoError::InvalidHandle)?.absorb(data),
+            Inner::HkdfExpand(state) => state.as_mut().ok_or(CryptoError::InvalidHandle)?.absorb(data),
+            Inner::AesGcm(_) | Inner::AesSiv(_) | Inner::CamelliaGcm(_) | Inner::Eax(_) => {
+                bail!(CryptoError::InvalidOperation)
+            }
+        }
+    }
+
+    pub fn squeeze(&mut self, out: &mut [u8]) -> Result<(), CryptoError> {
+        match &mut self.inner {
+            Inner::Hash(state) => {
+                let state = state.take().ok_or(CryptoError::InvalidHandle)?;
+                state.squeeze(out)
+            }
+            Inner::Noise(state) => state.squeeze(out),
+            Inner::Argon2(state) => {
+                let state = state.take().ok_or(CryptoError::InvalidHandle)?;
+                state.squeeze(out)
+            }
+            Inner::HkdfExpand(state) => {
+                let state = state.take().ok_or(CryptoError::InvalidHandle)?;
+                state.squeeze(out)
+            }
+            _ => bail!(CryptoError::InvalidOperation),
+        }
+    }
+
+    pub fn squeeze_tag(&mut self) -> Result<SymmetricTag, CryptoError> {
+        match &self.inner {
+            Inner::Hmac(state) => state.as_ref().ok_or(CryptoError::InvalidHandle)?.squeeze_tag(),
+            _ => bail!(CryptoError::InvalidOperation),
+        }
+    }
+
+    pub fn squeeze_key(&mut self, alg: SymmetricAlgorithm) -> Result<SymmetricKey, CryptoError> {
+        match &mut self.inner {
+            Inner::HkdfExtract(state) => {
+                let state = state.take().ok_or(CryptoError::InvalidHandle)?;
+                state.squeeze_key(alg)
+            }
+            _ => bail!(CryptoError::InvalidOperation),
+        }
+    }
+
+    pub fn encrypt(&mut self, out: &mut [u8], data: &[u8]) -> Result<usize, CryptoError> {
+        match &mut self.inner {
+            Inner::AesGcm(state) => state.encrypt(out, data),
+            Inner::AesSiv(state) => state.encrypt(out, data),
+            Inner::CamelliaGcm(state) => state.encrypt(out, data),
+            Inner::Eax(state) => state.encrypt(out, data),
+            _ => bail!(CryptoError::InvalidOperation),
+        }
+    }
+
+    pub fn encrypt_detached(&mut self, out: &mut [u8], data: &[u8]) -> Result<SymmetricTag, CryptoError> {
+        match &mut self.inner {
+            Inner::AesGcm(state) => state.encrypt_detached(out, data),
+            Inner::AesSiv(state) => state.encrypt_detached(out, data),
+            Inner::CamelliaGcm(state) => state.encrypt_detached(out, data),
+            Inner::Eax(state) => state.encrypt_detached(out, data),
+            _ => bail!(CryptoError::InvalidOperation),
+        }
+    }
+
+    pub fn decrypt(&mut self, out: &mut [u8], data: &[u8]) -> Result<usize, CryptoError> {
+        match &mut self.inner {
+            Inner::AesGcm(state) => state.decrypt(out, data),
+            Inner::AesSiv(state) => state.decrypt(out, data),
+            Inner::CamelliaGcm(state) => state.decrypt(out, data),
+            Inner::Eax(state) => state.decrypt(out, data),
+            _ => bail!(CryptoError::InvalidOperation),
+        }
+    }
+
+    pub fn decrypt_detached(
+        &mut self,
+        out: &mut [u8],
+        data: &[u8],
+        raw_tag: &[u8],
+    ) -> Result<usize, CryptoError> {
+        match &mut self.inner {
+            Inner::AesGcm(state) => state.decrypt_detached(out, data, raw_tag),
+            Inner::AesSiv(state) => state.decrypt_detached(out, data, raw_tag),
+            Inner::CamelliaGcm(state) => state.decrypt_detached(out, data, raw_tag),
+            Inner::Eax(state) => state.decrypt_detached(out, data, raw_tag),
+            _ => bail!(CryptoError::InvalidOperation),
+        }
+    }
+
+    /// Noise's MixKey: folds caller-supplied input key material (e.g. a DH
+    /// output) into the chaining key, advancing the handshake's forward
+    /// secrecy. Independent of any transcript data passed to `absorb`.
+    pub fn ratchet(&mut self, ikm: &[u8]) -> Result<(), CryptoError> {
+        match &mut self.inner {
+            Inner::Noise(state) => state.ratchet(ikm),
+            _ => bail!(CryptoError::InvalidOperation),
+        }
+    }
+
+    /// Ends a Noise handshake, deriving one AEAD transport state per
+    /// direction from the final chaining key. Each returned state auto-
+    /// increments its own nonce on every `encrypt`/`decrypt`.
+    pub fn split(&self) -> Result<(SymmetricState, SymmetricState), CryptoError> {
+        match &self.inner {
+            Inner::Noise(state) => {
+                let (raw_k1, raw_k2) = state.split()?;
+                Ok((Self::transport_state(raw_k1)?, Self::transport_state(raw_k2)?))
+            }
+            _ => bail!(CryptoError::InvalidOperation),
+        }
+    }
+
+    fn transport_state(raw_key: Vec<u8>) -> Result<SymmetricState, CryptoError> {
+        if raw_key.len() < 32 {
+            bail!(CryptoError::InvalidLength);
+        }
+        let key = SymmetricKey::new(SymmetricAlgorithm::Aes256Gcm, raw_key[..32].to_vec());
+        Ok(SymmetricState {
+            alg: SymmetricAlgorithm::Aes256Gcm,
+            options: SymmetricOptions::default(),
+            inner: Inner::AesGcm(AesGcmState::new_auto(&key)),
+        })
+    }
+}
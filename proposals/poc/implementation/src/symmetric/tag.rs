@@ -0,0 +1,47 @@
+use crate::error::*;
+
+#[derive(Clone, Debug)]
+pub struct SymmetricTag {
+    raw: Vec<u8>,
+}
+
+impl SymmetricTag {
+    pub fn new(raw: Vec<u8>) -> Self {
+        SymmetricTag { raw }
+    }
+
+    pub fn len(&self) -> usize {
+        self.raw.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.raw.is_empty()
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.raw
+    }
+
+    pub fn verify(&self, expected_raw: &[u8]) -> Result<(), CryptoError> {
+        if !is_equal(&self.raw, expected_raw) {
+            bail!(CryptoError::InvalidTag);
+        }
+        Ok(())
+    }
+}
+
+/// Branch-free, length-checked equality for MACs and AEAD tags. A plain
+/// `==`/slice comparison short-circuits on the first mismatching byte, which
+/// leaks how many leading bytes of a forged tag were correct; this ORs the
+/// XOR of every byte pair into a single accumulator and only branches once,
+/// at the end.
+pub fn is_equal(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}